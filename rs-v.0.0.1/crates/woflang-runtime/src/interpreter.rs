@@ -5,15 +5,99 @@
 //! execution state (stack, scopes) and provides the context for operation handlers.
 
 use crate::{Registry, Token, TokenKind, Tokenizer};
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::fs;
 use std::io::{self, BufRead, Write};
 use std::path::Path;
+use std::sync::Arc;
 use woflang_core::{
-    BlockId, BlockRegistry, BlockStack, BlockType, InterpreterContext, 
+    BlockId, BlockRegistry, BlockStack, BlockType, InterpreterContext,
     Result, ScopeStack, Span, WofError, WofStack, WofValue,
 };
 
+/// A cloned, call-ready operation handler, resolved once at compile time
+/// instead of being looked up by name on every execution.
+type Handler = Arc<dyn Fn(&mut Interpreter) -> Result<()> + Send + Sync>;
+
+// ═══════════════════════════════════════════════════════════════════════
+// SYMBOL INTERNING
+// ═══════════════════════════════════════════════════════════════════════
+
+/// A small integer standing in for an interned symbol's text.
+///
+/// Comparing `SymId`s is a single integer comparison, avoiding the
+/// repeated string hashing that bare `&str` dispatch requires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SymId(u32);
+
+/// Maps symbol text to small, stable integer ids.
+///
+/// Interning the same text twice returns the same [`SymId`], so once a
+/// token has been interned, all further comparisons against it (keyword
+/// checks, registry lookups) are array-index or integer operations
+/// instead of string hashing.
+#[derive(Debug, Default)]
+struct Interner {
+    names: Vec<String>,
+    ids: HashMap<String, u32>,
+}
+
+impl Interner {
+    fn intern(&mut self, name: &str) -> SymId {
+        if let Some(&id) = self.ids.get(name) {
+            return SymId(id);
+        }
+        let id = self.names.len() as u32;
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        SymId(id)
+    }
+
+    fn resolve(&self, id: SymId) -> &str {
+        &self.names[id.0 as usize]
+    }
+}
+
+/// Ids for the built-in keywords and their aliases, pre-interned once at
+/// construction so `compile_symbol` can compare ids instead of matching
+/// on the token's raw text.
+#[derive(Debug)]
+struct Keywords {
+    read: [SymId; 3],
+    define: [SymId; 3],
+    set: [SymId; 3],
+    if_: [SymId; 2],
+    else_: [SymId; 2],
+    block_open: SymId,
+    block_close: SymId,
+    loop_open: [SymId; 2],
+    loop_close: [SymId; 3],
+    word_open: SymId,
+    parse_timestamp: SymId,
+}
+
+impl Keywords {
+    fn new(interner: &mut Interner) -> Self {
+        Self {
+            read: [interner.intern("読"), interner.intern("load"), interner.intern("get")],
+            define: [interner.intern("字"), interner.intern("define"), interner.intern("let")],
+            set: [interner.intern("支"), interner.intern("set"), interner.intern("store")],
+            if_: [interner.intern("若"), interner.intern("if")],
+            else_: [interner.intern("或"), interner.intern("else")],
+            block_open: interner.intern("⺆"),
+            block_close: interner.intern("⺘"),
+            loop_open: [interner.intern("⟳"), interner.intern("loop")],
+            loop_close: [
+                interner.intern("再"),
+                interner.intern("again"),
+                interner.intern("until"),
+            ],
+            word_open: interner.intern(":"),
+            parse_timestamp: interner.intern("parse-timestamp"),
+        }
+    }
+}
+
 /// The Woflang interpreter.
 ///
 /// Manages the execution state and operation dispatch for a Woflang
@@ -44,16 +128,35 @@ pub struct Interpreter {
     blocks: BlockRegistry,
     /// Block nesting stack.
     block_stack: BlockStack,
-    /// Token buffer for lookahead/control flow.
-    token_buffer: VecDeque<OwnedToken>,
-    /// Current instruction pointer (for compiled mode).
+    /// Current instruction pointer into the chunk presently executing.
     ip: usize,
-    /// Skip mode depth (for skipping else branches etc).
-    skip_depth: usize,
+    /// User-defined words (`: name … ;`), each compiled to its own chunk.
+    words: HashMap<String, Chunk>,
+    /// Maximum depth of `return_stack` before a word call is rejected as
+    /// a runtime error instead of overflowing the host stack.
+    max_return_depth: usize,
+    /// Linear memory region backing `!8`/`@8`/`!64`/`@64`. Empty (and so
+    /// inert) until [`Interpreter::enable_memory`] is called.
+    memory: Vec<u8>,
+    /// Host-provided syscall dispatch for `syscall3`, if installed.
+    syscalls: Option<Box<dyn SyscallTable>>,
+    /// Symbol interner backing [`Interpreter::intern`]/[`Interpreter::resolve`].
+    interner: Interner,
+    /// Pre-interned ids for the built-in keywords, computed once at
+    /// construction.
+    keywords: Keywords,
+    /// Registered handlers keyed by `SymId`, populated alongside
+    /// `registry` so dispatch by id is an array index instead of a hash
+    /// lookup. Indexed by `SymId.0`; entries for ids that were interned
+    /// but never registered are `None`.
+    registry_cache: Vec<Option<Handler>>,
     /// Debug mode: print stack after each line.
     pub debug: bool,
 }
 
+/// Default cap on `return_stack` depth; see [`Interpreter::set_max_return_depth`].
+const DEFAULT_MAX_RETURN_DEPTH: usize = 1024;
+
 /// An owned token for buffering during control flow.
 #[derive(Debug, Clone)]
 pub struct OwnedToken {
@@ -75,6 +178,234 @@ impl<'a> From<Token<'a>> for OwnedToken {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════
+// BYTECODE
+// ═══════════════════════════════════════════════════════════════════════
+
+/// A single bytecode instruction.
+///
+/// `Chunk`s are a flat `Vec<OpCode>`; control flow is expressed as
+/// absolute jumps into that vector rather than by re-scanning tokens.
+#[derive(Debug, Clone)]
+pub enum OpCode {
+    /// Push `chunk.constants[_]` onto the stack.
+    PushConst(usize),
+    /// Call a registry handler resolved at compile time, `chunk.calls[_]`.
+    CallOp(usize),
+    /// Dispatch `chunk.symbols[_]` dynamically at run time (auto-loaded
+    /// variable or bare symbol) because it did not resolve to a handler
+    /// when the chunk was compiled.
+    Dispatch(usize),
+    /// Call the user-defined word named `chunk.symbols[_]`.
+    CallWord(usize),
+    /// Read a variable named `chunk.symbols[_]` and push its value.
+    LoadVar(usize),
+    /// Pop a value and define it as a new variable named `chunk.symbols[_]`.
+    DefineVar(usize),
+    /// Pop a value and store it into the existing variable `chunk.symbols[_]`.
+    SetVar(usize),
+    /// Unconditional jump to an absolute instruction index.
+    Jump(usize),
+    /// Pop a value; jump to the target if it is falsy, else fall through.
+    JumpIfFalse(usize),
+    /// Push a new block scope of the given type.
+    EnterScope(BlockType),
+    /// Pop the current block scope.
+    LeaveScope,
+    /// Stop executing the current chunk.
+    Return,
+    /// Pop a value, coerce it, and push the result.
+    Convert(Conversion),
+}
+
+/// A coercion between `WofValue` representations.
+///
+/// Backs the `to-int`, `to-float`, `to-string`, `to-bool`, and
+/// `parse-timestamp` operations. `ParseTimestamp` carries its format
+/// string because it is read from the token immediately following
+/// `parse-timestamp` at compile time, not from the stack.
+#[derive(Debug, Clone)]
+enum Conversion {
+    ToInt,
+    ToFloat,
+    ToString,
+    ToBool,
+    ParseTimestamp(String),
+}
+
+/// A compiled, reusable unit of Woflang bytecode.
+///
+/// Produced by [`Interpreter::compile`] and executed by
+/// [`Interpreter::run_chunk`]. Compiling once and running many times
+/// avoids re-tokenizing and re-hashing every symbol on each pass, which
+/// matters for loops and repeated scripts.
+#[derive(Debug, Clone, Default)]
+pub struct Chunk {
+    /// The instruction stream.
+    code: Vec<OpCode>,
+    /// Literal values referenced by `PushConst`.
+    constants: Vec<WofValue>,
+    /// Variable/symbol names referenced by `Dispatch`, `LoadVar`,
+    /// `DefineVar`, and `SetVar`.
+    symbols: Vec<String>,
+    /// Handlers resolved once at compile time, referenced by `CallOp`.
+    calls: Vec<Handler>,
+}
+
+impl Chunk {
+    /// Create an empty chunk.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of instructions in this chunk.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.code.len()
+    }
+
+    /// Whether this chunk has no instructions.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.code.is_empty()
+    }
+
+    /// Append an instruction, returning its index for later backpatching.
+    fn push_op(&mut self, op: OpCode) -> usize {
+        self.code.push(op);
+        self.code.len() - 1
+    }
+
+    /// Append a `PushConst` for `value`.
+    fn push_const(&mut self, value: WofValue) {
+        let idx = self.constants.len();
+        self.constants.push(value);
+        self.push_op(OpCode::PushConst(idx));
+    }
+
+    /// Intern a name into the symbol pool, returning its index.
+    fn intern_symbol(&mut self, name: &str) -> usize {
+        if let Some(idx) = self.symbols.iter().position(|s| s == name) {
+            return idx;
+        }
+        self.symbols.push(name.to_string());
+        self.symbols.len() - 1
+    }
+
+    /// Patch a previously emitted `Jump`/`JumpIfFalse` placeholder to
+    /// point at `target`.
+    fn patch_jump(&mut self, pos: usize, target: usize) {
+        match &mut self.code[pos] {
+            OpCode::Jump(t) | OpCode::JumpIfFalse(t) => *t = target,
+            other => unreachable!("patch_jump on non-jump opcode: {other:?}"),
+        }
+    }
+}
+
+/// Tracks an open, not-yet-closed block while compiling, so `⺘` knows
+/// what to backpatch.
+#[derive(Debug)]
+enum Frame {
+    /// An open `若`/`if`. `else_jump` is set once `或`/`else` is seen.
+    If {
+        jump_if_false: usize,
+        else_jump: Option<usize>,
+    },
+    /// An open `⺆` generic block.
+    Block,
+    /// An open `⟳`/`loop`, recording the instruction index the matching
+    /// `再`/`again`/`until` should jump back to.
+    Loop { start: usize },
+}
+
+/// Which kind of Graphviz graph [`Interpreter::to_dot`] should render.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphKind {
+    /// A `digraph` with parent-to-child nesting edges.
+    Directed,
+    /// A `graph` with undirected nesting edges.
+    Undirected,
+}
+
+/// Parse `text` against a `strftime`-style `format` (supporting `%Y`,
+/// `%m`, `%d`, `%H`, `%M`, `%S`; other characters in `format` must match
+/// `text` literally), returning Unix seconds.
+fn parse_timestamp(text: &str, format: &str) -> Result<i64> {
+    let mut year = 1970i64;
+    let mut month = 1i64;
+    let mut day = 1i64;
+    let mut hour = 0i64;
+    let mut minute = 0i64;
+    let mut second = 0i64;
+
+    let mut chars = text.chars().peekable();
+    let mut spec = format.chars().peekable();
+    let bad_format = || WofError::Runtime(format!("cannot parse \"{text}\" with format \"{format}\""));
+
+    while let Some(fc) = spec.next() {
+        if fc != '%' {
+            if chars.next() != Some(fc) {
+                return Err(bad_format());
+            }
+            continue;
+        }
+
+        let directive = spec.next().ok_or_else(bad_format)?;
+        let width = match directive {
+            'Y' => 4,
+            'm' | 'd' | 'H' | 'M' | 'S' => 2,
+            other => {
+                return Err(WofError::Runtime(format!(
+                    "unsupported parse-timestamp directive %{other}"
+                )))
+            }
+        };
+
+        let mut digits = String::with_capacity(width);
+        for _ in 0..width {
+            match chars.next() {
+                Some(c) if c.is_ascii_digit() => digits.push(c),
+                _ => return Err(bad_format()),
+            }
+        }
+        let value: i64 = digits.parse().map_err(|_| bad_format())?;
+        match directive {
+            'Y' => year = value,
+            'm' => month = value,
+            'd' => day = value,
+            'H' => hour = value,
+            'M' => minute = value,
+            'S' => second = value,
+            _ => unreachable!(),
+        }
+    }
+
+    Ok(days_from_civil(year, month, day) * 86_400 + hour * 3600 + minute * 60 + second)
+}
+
+/// Days since the Unix epoch for a Gregorian calendar date, via Howard
+/// Hinnant's `days_from_civil` construction.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let year_of_era = y - era * 400;
+    let month_shifted = (month + 9) % 12;
+    let day_of_year = (153 * month_shifted + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Host-provided dispatch for the `syscall3` operation.
+///
+/// Lets embedders expose primitives such as write-to-stdout
+/// (`number, buffer_ptr, len`) without hardcoding I/O into the core
+/// interpreter. Installed with [`Interpreter::set_syscall_table`].
+pub trait SyscallTable: Send + Sync {
+    /// Handle a syscall, returning the value `syscall3` pushes back.
+    fn call(&self, interp: &mut Interpreter, number: i64, args: [i64; 3]) -> Result<i64>;
+}
+
 impl Default for Interpreter {
     fn default() -> Self {
         Self::new()
@@ -85,6 +416,8 @@ impl Interpreter {
     /// Create a new interpreter with an empty registry.
     #[must_use]
     pub fn new() -> Self {
+        let mut interner = Interner::default();
+        let keywords = Keywords::new(&mut interner);
         Self {
             stack: WofStack::with_capacity(64),
             return_stack: Vec::with_capacity(16),
@@ -92,9 +425,14 @@ impl Interpreter {
             scopes: ScopeStack::new(),
             blocks: BlockRegistry::new(),
             block_stack: BlockStack::new(),
-            token_buffer: VecDeque::new(),
             ip: 0,
-            skip_depth: 0,
+            words: HashMap::new(),
+            max_return_depth: DEFAULT_MAX_RETURN_DEPTH,
+            memory: Vec::new(),
+            syscalls: None,
+            interner,
+            keywords,
+            registry_cache: Vec::new(),
             debug: false,
         }
     }
@@ -102,6 +440,8 @@ impl Interpreter {
     /// Create an interpreter with a pre-configured registry.
     #[must_use]
     pub fn with_registry(registry: Registry<Self>) -> Self {
+        let mut interner = Interner::default();
+        let keywords = Keywords::new(&mut interner);
         Self {
             stack: WofStack::with_capacity(64),
             return_stack: Vec::with_capacity(16),
@@ -109,13 +449,27 @@ impl Interpreter {
             scopes: ScopeStack::new(),
             blocks: BlockRegistry::new(),
             block_stack: BlockStack::new(),
-            token_buffer: VecDeque::new(),
             ip: 0,
-            skip_depth: 0,
+            words: HashMap::new(),
+            max_return_depth: DEFAULT_MAX_RETURN_DEPTH,
+            memory: Vec::new(),
+            syscalls: None,
+            interner,
+            keywords,
+            registry_cache: Vec::new(),
             debug: false,
         }
     }
 
+    /// Set the maximum `return_stack` depth for word calls.
+    ///
+    /// Exceeding this bounds recursion with a [`WofError::Runtime`]
+    /// instead of overflowing the host stack. Defaults to
+    /// `DEFAULT_MAX_RETURN_DEPTH`.
+    pub fn set_max_return_depth(&mut self, depth: usize) {
+        self.max_return_depth = depth;
+    }
+
     /// Get a reference to the registry.
     #[must_use]
     pub fn registry(&self) -> &Registry<Self> {
@@ -133,7 +487,47 @@ impl Interpreter {
     where
         F: Fn(&mut Self) -> Result<()> + Send + Sync + 'static,
     {
-        self.registry.register(name, handler);
+        let name = name.into();
+        let id = self.intern(&name);
+        self.registry.register(name.clone(), handler);
+        let cached = self.registry.get_cloned(&name);
+        self.set_registry_cache(id, cached);
+    }
+
+    /// Intern `name`, returning a stable [`SymId`]. Interning the same
+    /// text again returns the same id.
+    pub fn intern(&mut self, name: &str) -> SymId {
+        self.interner.intern(name)
+    }
+
+    /// Resolve a previously interned id back to its text.
+    #[must_use]
+    pub fn resolve(&self, id: SymId) -> &str {
+        self.interner.resolve(id)
+    }
+
+    /// Store `handler` in `registry_cache` under `id`, growing the cache
+    /// if needed.
+    fn set_registry_cache(&mut self, id: SymId, handler: Option<Handler>) {
+        let idx = id.0 as usize;
+        if idx >= self.registry_cache.len() {
+            self.registry_cache.resize(idx + 1, None);
+        }
+        self.registry_cache[idx] = handler;
+    }
+
+    /// Look up the handler for `name`/`id`, preferring the `SymId`-indexed
+    /// cache (an array access) and falling back to the string-keyed
+    /// registry for handlers installed directly through `Registry`
+    /// (e.g. via [`Interpreter::with_registry`]) rather than through
+    /// [`Interpreter::register`]. A fallback hit is cached for next time.
+    fn lookup_handler(&mut self, id: SymId, name: &str) -> Option<Handler> {
+        if let Some(Some(handler)) = self.registry_cache.get(id.0 as usize) {
+            return Some(handler.clone());
+        }
+        let handler = self.registry.get_cloned(name)?;
+        self.set_registry_cache(id, Some(handler.clone()));
+        Some(handler)
     }
 
     // ═══════════════════════════════════════════════════════════════
@@ -209,6 +603,33 @@ impl Interpreter {
         self.block_stack.depth()
     }
 
+    /// Render the registered block structure as a Graphviz DOT graph,
+    /// one node per [`BlockId`] labeled with its type and source span,
+    /// with an edge from each block's parent to the block.
+    ///
+    /// `block_depth()` collapses this down to a single number; `to_dot`
+    /// gives the whole nesting shape so it can be piped into `dot`.
+    #[must_use]
+    pub fn to_dot(&self, kind: GraphKind) -> String {
+        let (header, edge) = match kind {
+            GraphKind::Directed => ("digraph", "->"),
+            GraphKind::Undirected => ("graph", "--"),
+        };
+
+        let mut out = format!("{header} blocks {{\n");
+        for (id, block) in self.blocks.iter() {
+            out.push_str(&format!(
+                "  \"{id:?}\" [label=\"{:?} {:?}\"];\n",
+                block.block_type, block.span
+            ));
+            if let Some(parent) = block.parent {
+                out.push_str(&format!("  \"{parent:?}\" {edge} \"{id:?}\";\n"));
+            }
+        }
+        out.push_str("}\n");
+        out
+    }
+
     // ═══════════════════════════════════════════════════════════════
     // RETURN STACK (for function calls)
     // ═══════════════════════════════════════════════════════════════
@@ -223,32 +644,149 @@ impl Interpreter {
         self.return_stack.pop()
     }
 
+    // ═══════════════════════════════════════════════════════════════
+    // LINEAR MEMORY & SYSCALLS
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Enable the linear memory subsystem and register its operations
+    /// (`!8`/`store8`, `@8`/`load8`, `!64`/`store64`, `@64`/`load64`,
+    /// `syscall3`) on the registry.
+    ///
+    /// `size` bytes are allocated and zero-initialized. Every memory
+    /// access is bounds-checked against this size.
+    pub fn enable_memory(&mut self, size: usize) {
+        self.memory.resize(size, 0);
+
+        self.register("!8", |ctx| {
+            let addr = ctx.pop()?.as_integer()? as usize;
+            let value = ctx.pop()?.as_integer()?;
+            ctx.store_bytes(addr, &[value as u8])
+        });
+        self.register("store8", |ctx| {
+            let addr = ctx.pop()?.as_integer()? as usize;
+            let value = ctx.pop()?.as_integer()?;
+            ctx.store_bytes(addr, &[value as u8])
+        });
+        self.register("@8", |ctx| {
+            let addr = ctx.pop()?.as_integer()? as usize;
+            let byte = ctx.load_bytes(addr, 1)?[0];
+            ctx.push(WofValue::integer(i64::from(byte)));
+            Ok(())
+        });
+        self.register("load8", |ctx| {
+            let addr = ctx.pop()?.as_integer()? as usize;
+            let byte = ctx.load_bytes(addr, 1)?[0];
+            ctx.push(WofValue::integer(i64::from(byte)));
+            Ok(())
+        });
+
+        self.register("!64", |ctx| {
+            let addr = ctx.pop()?.as_integer()? as usize;
+            let value = ctx.pop()?.as_integer()?;
+            ctx.store_bytes(addr, &value.to_le_bytes())
+        });
+        self.register("store64", |ctx| {
+            let addr = ctx.pop()?.as_integer()? as usize;
+            let value = ctx.pop()?.as_integer()?;
+            ctx.store_bytes(addr, &value.to_le_bytes())
+        });
+        self.register("@64", |ctx| {
+            let addr = ctx.pop()?.as_integer()? as usize;
+            let bytes = ctx.load_bytes(addr, 8)?;
+            let value = i64::from_le_bytes(bytes.try_into().expect("8 bytes"));
+            ctx.push(WofValue::integer(value));
+            Ok(())
+        });
+        self.register("load64", |ctx| {
+            let addr = ctx.pop()?.as_integer()? as usize;
+            let bytes = ctx.load_bytes(addr, 8)?;
+            let value = i64::from_le_bytes(bytes.try_into().expect("8 bytes"));
+            ctx.push(WofValue::integer(value));
+            Ok(())
+        });
+
+        self.register("syscall3", |ctx| {
+            let arg2 = ctx.pop()?.as_integer()?;
+            let arg1 = ctx.pop()?.as_integer()?;
+            let arg0 = ctx.pop()?.as_integer()?;
+            let number = ctx.pop()?.as_integer()?;
+            let table = ctx
+                .syscalls
+                .take()
+                .ok_or_else(|| WofError::Runtime("no syscall table installed".into()))?;
+            let result = table.call(ctx, number, [arg0, arg1, arg2]);
+            ctx.syscalls = Some(table);
+            ctx.push(WofValue::integer(result?));
+            Ok(())
+        });
+    }
+
+    /// The current size of the linear memory region, in bytes.
+    #[must_use]
+    pub fn memory_size(&self) -> usize {
+        self.memory.len()
+    }
+
+    /// Install the syscall dispatch table used by `syscall3`.
+    pub fn set_syscall_table(&mut self, table: Box<dyn SyscallTable>) {
+        self.syscalls = Some(table);
+    }
+
+    /// Write `bytes` into memory starting at `addr`, bounds-checked
+    /// against the configured memory size.
+    fn store_bytes(&mut self, addr: usize, bytes: &[u8]) -> Result<()> {
+        let end = addr
+            .checked_add(bytes.len())
+            .filter(|&end| end <= self.memory.len())
+            .ok_or_else(|| WofError::Runtime("memory access out of bounds".into()))?;
+        self.memory[addr..end].copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Read `len` bytes from memory starting at `addr`, bounds-checked
+    /// against the configured memory size.
+    fn load_bytes(&self, addr: usize, len: usize) -> Result<&[u8]> {
+        let end = addr
+            .checked_add(len)
+            .ok_or_else(|| WofError::Runtime("memory access out of bounds".into()))?;
+        self.memory
+            .get(addr..end)
+            .ok_or_else(|| WofError::Runtime("memory access out of bounds".into()))
+    }
+
+    // ═══════════════════════════════════════════════════════════════
+    // TYPE COERCION
+    // ═══════════════════════════════════════════════════════════════
+
+    /// Register `to-int`, `to-float`, `to-string`, and `to-bool` on the
+    /// registry. `parse-timestamp` needs no registration: it is compiled
+    /// directly to `OpCode::Convert` because it reads its format string
+    /// from the token stream rather than the stack.
+    pub fn install_conversion_ops(&mut self) {
+        self.register("to-int", |ctx| ctx.convert(&Conversion::ToInt));
+        self.register("to-float", |ctx| ctx.convert(&Conversion::ToFloat));
+        self.register("to-string", |ctx| ctx.convert(&Conversion::ToString));
+        self.register("to-bool", |ctx| ctx.convert(&Conversion::ToBool));
+    }
+
     // ═══════════════════════════════════════════════════════════════
     // EXECUTION
     // ═══════════════════════════════════════════════════════════════
 
     /// Execute a single line of Woflang code.
     ///
-    /// The line is tokenized and each token is dispatched through the
-    /// interpreter. Errors are returned immediately; partial execution
-    /// may have modified the stack.
+    /// The line is compiled to a [`Chunk`] and immediately run. For code
+    /// that executes more than once (loops, repeated scripts), prefer
+    /// calling [`Interpreter::compile`] once and [`Interpreter::run_chunk`]
+    /// many times instead.
     pub fn exec_line(&mut self, line: &str) -> Result<()> {
         let trimmed = line.trim();
         if trimmed.is_empty() {
             return Ok(());
         }
 
-        // Buffer all tokens for lookahead
-        let tokenizer = Tokenizer::new(trimmed);
-        self.token_buffer.clear();
-        for token in tokenizer {
-            self.token_buffer.push_back(token.into());
-        }
-
-        // Process tokens
-        while let Some(token) = self.token_buffer.pop_front() {
-            self.dispatch_owned_token(&token)?;
-        }
+        let chunk = self.compile(trimmed)?;
+        self.run_chunk(&chunk)?;
 
         if self.debug {
             eprintln!("[debug] stack: {}", self.stack);
@@ -258,6 +796,360 @@ impl Interpreter {
         Ok(())
     }
 
+    /// Compile Woflang source into a reusable [`Chunk`].
+    ///
+    /// Registry lookups for bare symbols happen here, once, rather than
+    /// on every execution of the chunk. `若`/`或`/`⺘` are lowered to
+    /// `JumpIfFalse`/`Jump` pairs using backpatching: the jump target is
+    /// unknown when the jump is emitted, so a placeholder is recorded and
+    /// patched in once the matching `或`/`⺘` is reached. `: name … ;`
+    /// word definitions are installed into the word table as soon as
+    /// they are compiled, Forth-style, so later code in the same source
+    /// can call them (including, for recursion, the word's own body).
+    pub fn compile(&mut self, source: &str) -> Result<Chunk> {
+        let mut tokens: VecDeque<OwnedToken> =
+            Tokenizer::new(source).map(OwnedToken::from).collect();
+        self.compile_tokens(&mut tokens, None)
+    }
+
+    /// Compile tokens until exhausted, or until `terminator` (a bare
+    /// symbol such as `;`) is consumed, whichever comes first.
+    fn compile_tokens(
+        &mut self,
+        tokens: &mut VecDeque<OwnedToken>,
+        terminator: Option<&str>,
+    ) -> Result<Chunk> {
+        let mut chunk = Chunk::new();
+        let mut frames: Vec<Frame> = Vec::new();
+
+        while let Some(token) = tokens.pop_front() {
+            if let Some(term) = terminator {
+                if token.kind == TokenKind::Symbol && token.text == term {
+                    if let Some(frame) = frames.pop() {
+                        return Err(WofError::Runtime(format!("unclosed block: {frame:?}")));
+                    }
+                    return Ok(chunk);
+                }
+            }
+
+            match token.kind {
+                TokenKind::Integer => {
+                    let value: i64 = token.text.parse()?;
+                    chunk.push_const(WofValue::integer(value));
+                }
+                TokenKind::Float => {
+                    let value: f64 = token.text.parse()?;
+                    chunk.push_const(WofValue::double(value));
+                }
+                TokenKind::String => {
+                    let value = crate::tokenizer::parse_string_literal(&token.text);
+                    chunk.push_const(WofValue::string(value));
+                }
+                TokenKind::Symbol => {
+                    self.compile_symbol(&token.text, tokens, &mut chunk, &mut frames)?;
+                }
+                TokenKind::Label => {
+                    if self.debug {
+                        let name = token.text.trim_start_matches(':');
+                        eprintln!("[debug] label defined: {name}");
+                    }
+                }
+                TokenKind::LabelRef => {
+                    let name = token.text.trim_start_matches('@');
+                    chunk.push_const(WofValue::symbol(format!("@{name}")));
+                }
+                TokenKind::Eof => {}
+            }
+        }
+
+        if terminator.is_some() {
+            return Err(WofError::Runtime(format!(
+                "unterminated word definition, expected {}",
+                terminator.unwrap()
+            )));
+        }
+
+        if let Some(frame) = frames.pop() {
+            return Err(WofError::Runtime(format!("unclosed block: {frame:?}")));
+        }
+
+        Ok(chunk)
+    }
+
+    /// Compile a single symbol token, consuming lookahead tokens for the
+    /// variable/control-flow forms that need an operand.
+    fn compile_symbol(
+        &mut self,
+        name: &str,
+        tokens: &mut VecDeque<OwnedToken>,
+        chunk: &mut Chunk,
+        frames: &mut Vec<Frame>,
+    ) -> Result<()> {
+        let id = self.intern(name);
+
+        if self.keywords.read.contains(&id) {
+            let var = Self::expect_symbol(tokens, name)?;
+            let idx = chunk.intern_symbol(&var);
+            chunk.push_op(OpCode::LoadVar(idx));
+        } else if self.keywords.define.contains(&id) {
+            let var = Self::expect_symbol(tokens, name)?;
+            let idx = chunk.intern_symbol(&var);
+            chunk.push_op(OpCode::DefineVar(idx));
+        } else if self.keywords.set.contains(&id) {
+            let var = Self::expect_symbol(tokens, name)?;
+            let idx = chunk.intern_symbol(&var);
+            chunk.push_op(OpCode::SetVar(idx));
+        } else if self.keywords.if_.contains(&id) {
+            let jump_if_false = chunk.push_op(OpCode::JumpIfFalse(usize::MAX));
+            chunk.push_op(OpCode::EnterScope(BlockType::If));
+            frames.push(Frame::If {
+                jump_if_false,
+                else_jump: None,
+            });
+        } else if self.keywords.else_.contains(&id) {
+            match frames.pop() {
+                Some(Frame::If {
+                    jump_if_false,
+                    else_jump: None,
+                }) => {
+                    chunk.push_op(OpCode::LeaveScope);
+                    let else_jump = chunk.push_op(OpCode::Jump(usize::MAX));
+                    chunk.patch_jump(jump_if_false, chunk.len());
+                    chunk.push_op(OpCode::EnterScope(BlockType::If));
+                    frames.push(Frame::If {
+                        jump_if_false,
+                        else_jump: Some(else_jump),
+                    });
+                }
+                _ => return Err(WofError::Runtime("或 without matching 若".into())),
+            }
+        } else if id == self.keywords.block_open {
+            chunk.push_op(OpCode::EnterScope(BlockType::Generic));
+            frames.push(Frame::Block);
+        } else if self.keywords.loop_open.contains(&id) {
+            chunk.push_op(OpCode::EnterScope(BlockType::Generic));
+            frames.push(Frame::Loop { start: chunk.len() });
+        } else if self.keywords.loop_close.contains(&id) {
+            match frames.pop() {
+                Some(Frame::Loop { start }) => {
+                    chunk.push_op(OpCode::JumpIfFalse(start));
+                    chunk.push_op(OpCode::LeaveScope);
+                }
+                _ => return Err(WofError::Runtime(format!("{name} without matching loop"))),
+            }
+        } else if id == self.keywords.word_open {
+            let word_name = Self::expect_symbol(tokens, ":")?;
+            let body = self.compile_tokens(tokens, Some(";"))?;
+            self.words.insert(word_name, body);
+        } else if id == self.keywords.parse_timestamp {
+            let format_err =
+                || WofError::Runtime("parse-timestamp requires a format string".into());
+            let fmt_token = tokens.pop_front().ok_or_else(format_err)?;
+            if fmt_token.kind != TokenKind::String {
+                return Err(format_err());
+            }
+            let fmt = crate::tokenizer::parse_string_literal(&fmt_token.text);
+            chunk.push_op(OpCode::Convert(Conversion::ParseTimestamp(fmt)));
+        } else if id == self.keywords.block_close {
+            match frames.pop() {
+                Some(Frame::Block) => {
+                    chunk.push_op(OpCode::LeaveScope);
+                }
+                Some(Frame::If {
+                    jump_if_false,
+                    else_jump,
+                }) => {
+                    chunk.push_op(OpCode::LeaveScope);
+                    match else_jump {
+                        Some(pos) => chunk.patch_jump(pos, chunk.len()),
+                        None => chunk.patch_jump(jump_if_false, chunk.len()),
+                    }
+                }
+                Some(Frame::Loop { .. }) => {
+                    return Err(WofError::Runtime(
+                        "a loop opened with ⟳/loop is closed with 再/again/until, not ⺘".into(),
+                    ));
+                }
+                None => return Err(WofError::Runtime("⺘ without matching block".into())),
+            }
+        } else if self.words.contains_key(name) {
+            let idx = chunk.intern_symbol(name);
+            chunk.push_op(OpCode::CallWord(idx));
+        } else if let Some(handler) = self.lookup_handler(id, name) {
+            let idx = chunk.calls.len();
+            chunk.calls.push(handler);
+            chunk.push_op(OpCode::CallOp(idx));
+        } else {
+            let idx = chunk.intern_symbol(name);
+            chunk.push_op(OpCode::Dispatch(idx));
+        }
+        Ok(())
+    }
+
+    /// Pop the next token and require it to be a bare symbol (a variable
+    /// name), restoring it to the front of the queue if it is not.
+    fn expect_symbol(tokens: &mut VecDeque<OwnedToken>, keyword: &str) -> Result<String> {
+        match tokens.pop_front() {
+            Some(next) if next.kind == TokenKind::Symbol => Ok(next.text),
+            Some(next) => {
+                tokens.push_front(next);
+                Err(WofError::Runtime(format!("{keyword} requires a variable name")))
+            }
+            None => Err(WofError::Runtime(format!("{keyword} requires a variable name"))),
+        }
+    }
+
+    /// Run a compiled chunk to completion.
+    pub fn run_chunk(&mut self, chunk: &Chunk) -> Result<()> {
+        self.ip = 0;
+        while self.ip < chunk.len() {
+            match &chunk.code[self.ip] {
+                OpCode::PushConst(idx) => {
+                    self.stack.push(chunk.constants[*idx].clone());
+                    self.ip += 1;
+                }
+                OpCode::CallOp(idx) => {
+                    let handler = chunk.calls[*idx].clone();
+                    handler(self)?;
+                    self.ip += 1;
+                }
+                OpCode::Dispatch(idx) => {
+                    let name = chunk.symbols[*idx].clone();
+                    self.dispatch_symbol(&name)?;
+                    self.ip += 1;
+                }
+                OpCode::CallWord(idx) => {
+                    let name = chunk.symbols[*idx].clone();
+                    self.call_word(&name)?;
+                    self.ip += 1;
+                }
+                OpCode::LoadVar(idx) => {
+                    let value = self.get_var(&chunk.symbols[*idx])?;
+                    self.stack.push(value);
+                    self.ip += 1;
+                }
+                OpCode::DefineVar(idx) => {
+                    let value = self.stack.pop()?;
+                    self.define_var(chunk.symbols[*idx].clone(), value);
+                    self.ip += 1;
+                }
+                OpCode::SetVar(idx) => {
+                    let value = self.stack.pop()?;
+                    self.set_var(&chunk.symbols[*idx].clone(), value)?;
+                    self.ip += 1;
+                }
+                OpCode::EnterScope(block_type) => {
+                    self.push_scope(*block_type);
+                    self.ip += 1;
+                }
+                OpCode::LeaveScope => {
+                    self.pop_scope();
+                    self.ip += 1;
+                }
+                OpCode::Jump(target) => {
+                    self.ip = *target;
+                }
+                OpCode::JumpIfFalse(target) => {
+                    let condition = self.stack.pop()?;
+                    if condition.is_truthy() {
+                        self.ip += 1;
+                    } else {
+                        self.ip = *target;
+                    }
+                }
+                OpCode::Return => break,
+                OpCode::Convert(conversion) => {
+                    let conversion = conversion.clone();
+                    self.convert(&conversion)?;
+                    self.ip += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Pop the top value, coerce it per `conversion`, and push the result.
+    fn convert(&mut self, conversion: &Conversion) -> Result<()> {
+        let value = self.stack.pop()?;
+        let converted = match conversion {
+            Conversion::ToInt => Self::to_int(&value)?,
+            Conversion::ToFloat => Self::to_float(&value)?,
+            Conversion::ToString => WofValue::string(value.to_string()),
+            Conversion::ToBool => WofValue::integer(i64::from(value.is_truthy())),
+            Conversion::ParseTimestamp(fmt) => {
+                let text = value
+                    .as_str()
+                    .map_err(|_| WofError::Runtime(format!("cannot convert {value} to timestamp")))?;
+                WofValue::integer(parse_timestamp(text, fmt)?)
+            }
+        };
+        self.stack.push(converted);
+        Ok(())
+    }
+
+    /// Name of `value`'s runtime representation, for conversion error
+    /// messages. `as_str` returns `Ok` for both strings and symbols, so
+    /// symbols are checked first to avoid being mislabeled as strings.
+    fn describe_type(value: &WofValue) -> &'static str {
+        if value.as_integer().is_ok() {
+            "integer"
+        } else if value.as_double().is_ok() {
+            "float"
+        } else if value.as_symbol().is_ok() {
+            "symbol"
+        } else {
+            "string"
+        }
+    }
+
+    /// Coerce `value` to an integer: already-integer, truncated float, or
+    /// a parsed numeric string.
+    fn to_int(value: &WofValue) -> Result<WofValue> {
+        if let Ok(i) = value.as_integer() {
+            return Ok(WofValue::integer(i));
+        }
+        if let Ok(f) = value.as_double() {
+            return Ok(WofValue::integer(f as i64));
+        }
+        if value.as_symbol().is_err() {
+            if let Ok(s) = value.as_str() {
+                return s
+                    .trim()
+                    .parse::<i64>()
+                    .map(WofValue::integer)
+                    .map_err(|_| WofError::Runtime(format!("cannot convert string \"{s}\" to integer")));
+            }
+        }
+        Err(WofError::Runtime(format!(
+            "cannot convert {} to integer",
+            Self::describe_type(value)
+        )))
+    }
+
+    /// Coerce `value` to a float: already-float, widened integer, or a
+    /// parsed numeric string.
+    fn to_float(value: &WofValue) -> Result<WofValue> {
+        if let Ok(f) = value.as_double() {
+            return Ok(WofValue::double(f));
+        }
+        if let Ok(i) = value.as_integer() {
+            return Ok(WofValue::double(i as f64));
+        }
+        if value.as_symbol().is_err() {
+            if let Ok(s) = value.as_str() {
+                return s
+                    .trim()
+                    .parse::<f64>()
+                    .map(WofValue::double)
+                    .map_err(|_| WofError::Runtime(format!("cannot convert string \"{s}\" to float")));
+            }
+        }
+        Err(WofError::Runtime(format!(
+            "cannot convert {} to float",
+            Self::describe_type(value)
+        )))
+    }
+
     /// Execute a script from a file.
     pub fn exec_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
         let content = fs::read_to_string(path.as_ref()).map_err(WofError::from)?;
@@ -270,18 +1162,49 @@ impl Interpreter {
     /// Run an interactive REPL (Read-Eval-Print Loop).
     ///
     /// Reads lines from stdin and executes them. Errors are printed
-    /// but do not terminate the REPL.
+    /// but do not terminate the REPL. A block opened with `若`/`⺆` (or a
+    /// loop opened with `loop`/`⟳`) on one line can be closed with `⺘`
+    /// (for a loop, `再`/`again`/`until`) on a later one:
+    /// while the open-block count is positive, lines are buffered behind
+    /// a `... ` continuation prompt instead of being run immediately. A
+    /// lone `.` while a block is pending cancels it instead of running.
     pub fn repl(&mut self) -> io::Result<()> {
         let stdin = io::stdin();
         let mut stdout = io::stdout();
 
         writeln!(stdout, "Woflang REPL v{}. Type 'exit' to quit.", woflang_core::VERSION)?;
 
+        let mut pending = String::new();
+        let mut open_blocks: i64 = 0;
+
         let reader = stdin.lock();
         for line in reader.lines() {
             let line = line?;
             let trimmed = line.trim();
 
+            if open_blocks > 0 {
+                if trimmed == "." {
+                    writeln!(stdout, "(cancelled)")?;
+                    pending.clear();
+                    open_blocks = 0;
+                    continue;
+                }
+
+                pending.push(' ');
+                pending.push_str(trimmed);
+                open_blocks += Self::block_delta(trimmed);
+
+                if open_blocks > 0 {
+                    write!(stdout, "... ")?;
+                    stdout.flush()?;
+                    continue;
+                }
+
+                let buffered = std::mem::take(&mut pending);
+                self.run_repl_line(&mut stdout, &buffered)?;
+                continue;
+            }
+
             if trimmed == "exit" || trimmed == "quit" {
                 writeln!(stdout, "Goodbye from woflang! 🐺")?;
                 break;
@@ -298,181 +1221,108 @@ impl Interpreter {
                 continue;
             }
 
-            match self.exec_line(&line) {
-                Ok(()) => {
-                    if !self.stack.is_empty() {
-                        if let Ok(top) = self.stack.peek() {
-                            writeln!(stdout, "→ {top}")?;
-                        }
-                    }
-                }
-                Err(e) => {
-                    writeln!(stdout, "Error: {e}")?;
-                }
+            if trimmed == ":graph" {
+                writeln!(stdout, "{}", self.to_dot(GraphKind::Directed))?;
+                continue;
             }
+
+            let delta = Self::block_delta(trimmed);
+            if delta > 0 {
+                pending.push_str(trimmed);
+                open_blocks = delta;
+                write!(stdout, "... ")?;
+                stdout.flush()?;
+                continue;
+            }
+
+            self.run_repl_line(&mut stdout, trimmed)?;
         }
 
         Ok(())
     }
 
-    /// Dispatch an owned token.
-    fn dispatch_owned_token(&mut self, token: &OwnedToken) -> Result<()> {
-        // If we're in skip mode, only process block delimiters
-        if self.skip_depth > 0 {
-            return self.handle_skip_mode(token);
-        }
-
-        match token.kind {
-            TokenKind::Integer => {
-                let value: i64 = token.text.parse()?;
-                self.stack.push(WofValue::integer(value));
-            }
-            TokenKind::Float => {
-                let value: f64 = token.text.parse()?;
-                self.stack.push(WofValue::double(value));
-            }
-            TokenKind::String => {
-                let value = crate::tokenizer::parse_string_literal(&token.text);
-                self.stack.push(WofValue::string(value));
-            }
-            TokenKind::Symbol => {
-                self.dispatch_symbol(&token.text)?;
-            }
-            TokenKind::Label => {
-                // Label definition (:name) - register for jump targets
-                let name = token.text.trim_start_matches(':');
-                // Store current position as label target
-                // For now, just acknowledge it
-                if self.debug {
-                    eprintln!("[debug] label defined: {name}");
+    /// Execute one fully-balanced REPL line and print its result.
+    fn run_repl_line(&mut self, stdout: &mut impl Write, line: &str) -> io::Result<()> {
+        match self.exec_line(line) {
+            Ok(()) => {
+                if !self.stack.is_empty() {
+                    if let Ok(top) = self.stack.peek() {
+                        writeln!(stdout, "→ {top}")?;
+                    }
                 }
             }
-            TokenKind::LabelRef => {
-                // Label reference (@name) - for jumps
-                let name = token.text.trim_start_matches('@');
-                self.stack.push(WofValue::symbol(format!("@{name}")));
+            Err(e) => {
+                writeln!(stdout, "Error: {e}")?;
             }
-            TokenKind::Eof => {}
         }
         Ok(())
     }
 
-    /// Handle tokens while in skip mode (skipping else branches etc).
-    fn handle_skip_mode(&mut self, token: &OwnedToken) -> Result<()> {
-        match token.text.as_str() {
-            "⺆" | "若" | "loop" | "⟳" => {
-                // Nested block - increase skip depth
-                self.skip_depth += 1;
-            }
-            "⺘" => {
-                // Block close - decrease skip depth
-                self.skip_depth = self.skip_depth.saturating_sub(1);
-            }
-            "或" if self.skip_depth == 1 => {
-                // We hit the else branch at our skip level - stop skipping
-                self.skip_depth = 0;
-            }
-            _ => {
-                // Skip this token
-            }
-        }
-        Ok(())
+    /// Net change in open-block count contributed by the words in `line`:
+    /// `+1` per `若`/`⺆`/`loop`/`⟳`, `-1` per `⺘` or `再`/`again`/`until`.
+    fn block_delta(line: &str) -> i64 {
+        line.split_whitespace()
+            .map(|word| match word {
+                "若" | "⺆" | "loop" | "⟳" => 1,
+                "⺘" | "再" | "again" | "until" => -1,
+                _ => 0,
+            })
+            .sum()
     }
 
-    /// Dispatch a symbol (operation or identifier).
+    /// Dispatch a bare symbol dynamically at run time.
+    ///
+    /// Used by [`OpCode::Dispatch`] for symbols that did not resolve to a
+    /// registered handler at compile time: a variable that may have been
+    /// defined since, or a name that should simply be pushed as itself.
     fn dispatch_symbol(&mut self, name: &str) -> Result<()> {
-        // Check for variable read syntax: 読 varname or just varname if it exists
-        if name == "読" || name == "load" || name == "get" {
-            // Next token should be variable name
-            if let Some(next) = self.token_buffer.pop_front() {
-                if next.kind == TokenKind::Symbol {
-                    let value = self.get_var(&next.text)?;
-                    self.stack.push(value);
-                    return Ok(());
-                }
-                // Put it back if not a symbol
-                self.token_buffer.push_front(next);
-            }
-            return Err(WofError::Runtime("読 requires a variable name".into()));
+        if self.words.contains_key(name) {
+            return self.call_word(name);
         }
 
-        // Check for variable define syntax: 字 varname value
-        if name == "字" || name == "define" || name == "let" {
-            if let Some(next) = self.token_buffer.pop_front() {
-                if next.kind == TokenKind::Symbol {
-                    let var_name = next.text.clone();
-                    // Value comes from stack
-                    let value = self.stack.pop()?;
-                    self.define_var(var_name, value);
-                    return Ok(());
-                }
-                self.token_buffer.push_front(next);
-            }
-            return Err(WofError::Runtime("字 requires a variable name".into()));
+        let id = self.intern(name);
+        if let Some(handler) = self.lookup_handler(id, name) {
+            return handler(self);
         }
 
-        // Check for variable set syntax: 支 varname
-        if name == "支" || name == "set" || name == "store" {
-            if let Some(next) = self.token_buffer.pop_front() {
-                if next.kind == TokenKind::Symbol {
-                    let value = self.stack.pop()?;
-                    self.set_var(&next.text, value)?;
-                    return Ok(());
-                }
-                self.token_buffer.push_front(next);
-            }
-            return Err(WofError::Runtime("支 requires a variable name".into()));
-        }
-
-        // Check for conditionals: 若 (if)
-        if name == "若" || name == "if" {
-            let condition = self.stack.pop()?;
-            let is_true = condition.is_truthy();
-            
-            if is_true {
-                // Execute the then branch, will skip else when we hit 或
-                self.push_scope(BlockType::If);
-            } else {
-                // Skip until we hit 或 (else) or ⺘ (end)
-                self.skip_depth = 1;
-            }
-            return Ok(());
-        }
-
-        // Check for else: 或
-        if name == "或" || name == "else" {
-            // If we're here, we executed the then branch - skip the else
-            self.skip_depth = 1;
-            return Ok(());
-        }
-
-        // Check for block delimiters
-        if name == "⺆" {
-            self.push_scope(BlockType::Generic);
-            return Ok(());
-        }
-
-        if name == "⺘" {
-            self.pop_scope();
-            return Ok(());
-        }
-
-        // Clone the handler Arc to avoid borrow conflict
-        if let Some(op) = self.registry.get_cloned(name) {
-            return op(self);
-        }
-
-        // Check if it's a defined variable - auto-load it
         if self.has_var(name) {
             let value = self.get_var(name)?;
             self.stack.push(value);
             return Ok(());
         }
 
-        // Not found: push as symbol
         self.stack.push(WofValue::symbol(name));
         Ok(())
     }
+
+    /// Invoke a user-defined word by name.
+    ///
+    /// Pushes the calling chunk's `ip` onto `return_stack`, runs the
+    /// word's compiled body, then restores `ip` so the caller resumes
+    /// right after the call. Depth is bounded by `max_return_depth` so
+    /// runaway recursion raises a runtime error instead of overflowing
+    /// the host stack.
+    fn call_word(&mut self, name: &str) -> Result<()> {
+        let body = self
+            .words
+            .get(name)
+            .cloned()
+            .ok_or_else(|| WofError::Runtime(format!("undefined word: {name}")))?;
+
+        if self.return_stack.len() >= self.max_return_depth {
+            return Err(WofError::Runtime(format!(
+                "return stack overflow calling {name} (max depth {})",
+                self.max_return_depth
+            )));
+        }
+
+        let caller_ip = self.ip;
+        self.push_return(caller_ip);
+        let result = self.run_chunk(&body);
+        self.pop_return();
+        self.ip = caller_ip;
+        result
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -599,4 +1449,224 @@ mod tests {
 
         assert!(interp.stack.is_empty());
     }
+
+    #[test]
+    fn compiled_chunk_runs_repeatedly() {
+        let mut interp = make_interp();
+        let chunk = interp.compile("1 dup +").unwrap();
+        interp.run_chunk(&chunk).unwrap();
+        interp.run_chunk(&chunk).unwrap();
+
+        assert_eq!(interp.stack.len(), 2);
+        assert_eq!(interp.stack.pop_integer().unwrap(), 2);
+        assert_eq!(interp.stack.pop_integer().unwrap(), 2);
+    }
+
+    #[test]
+    fn if_else_backpatching() {
+        let mut interp = make_interp();
+        interp.exec_line("1 若 10 或 20 ⺘").unwrap();
+        assert_eq!(interp.stack.pop_integer().unwrap(), 10);
+
+        interp.exec_line("0 若 10 或 20 ⺘").unwrap();
+        assert_eq!(interp.stack.pop_integer().unwrap(), 20);
+    }
+
+    #[test]
+    fn unclosed_block_is_a_compile_error() {
+        let mut interp = make_interp();
+        assert!(interp.exec_line("1 若 10").is_err());
+    }
+
+    #[test]
+    fn word_definition_and_call() {
+        let mut interp = make_interp();
+        interp.exec_line(": twice dup + ;").unwrap();
+        interp.exec_line("5 twice").unwrap();
+
+        assert_eq!(interp.stack.pop_integer().unwrap(), 10);
+    }
+
+    #[test]
+    fn loop_runs_until_condition_is_true() {
+        let mut interp = make_interp();
+        // `ge3` peeks the counter and pushes a truthy flag on top of it,
+        // leaving the counter itself for the next iteration (or as the
+        // final result once the loop exits).
+        interp.register("ge3", |ctx| {
+            let top = ctx.peek()?.as_integer()?;
+            ctx.push(WofValue::integer(i64::from(top >= 3)));
+            Ok(())
+        });
+        interp.exec_line("0 ⟳ 1 + ge3 再").unwrap();
+
+        assert_eq!(interp.stack.pop_integer().unwrap(), 3);
+    }
+
+    #[test]
+    fn return_stack_overflow_on_deep_recursion() {
+        let mut interp = make_interp();
+        interp.set_max_return_depth(4);
+        interp.exec_line(": recur recur ;").unwrap();
+
+        assert!(interp.exec_line("recur").is_err());
+    }
+
+    #[test]
+    fn memory_store_and_load_round_trip() {
+        let mut interp = make_interp();
+        interp.enable_memory(16);
+
+        interp.exec_line("255 0 !8").unwrap();
+        interp.exec_line("0 @8").unwrap();
+        assert_eq!(interp.stack.pop_integer().unwrap(), 255);
+
+        interp.exec_line("-1 8 !64").unwrap();
+        interp.exec_line("8 @64").unwrap();
+        assert_eq!(interp.stack.pop_integer().unwrap(), -1);
+    }
+
+    #[test]
+    fn memory_access_out_of_bounds_is_an_error() {
+        let mut interp = make_interp();
+        interp.enable_memory(4);
+
+        assert!(interp.exec_line("1 100 !8").is_err());
+    }
+
+    struct EchoSyscalls;
+
+    impl SyscallTable for EchoSyscalls {
+        fn call(&self, _interp: &mut Interpreter, number: i64, args: [i64; 3]) -> Result<i64> {
+            Ok(number + args[0] + args[1] + args[2])
+        }
+    }
+
+    #[test]
+    fn syscall3_dispatches_through_the_syscall_table() {
+        let mut interp = make_interp();
+        interp.enable_memory(4);
+        interp.set_syscall_table(Box::new(EchoSyscalls));
+
+        interp.exec_line("1 2 3 4 syscall3").unwrap();
+        assert_eq!(interp.stack.pop_integer().unwrap(), 10);
+    }
+
+    #[test]
+    fn conversion_ops_coerce_between_representations() {
+        let mut interp = make_interp();
+        interp.install_conversion_ops();
+
+        interp.exec_line(r#""42" to-int"#).unwrap();
+        assert_eq!(interp.stack.pop_integer().unwrap(), 42);
+
+        interp.exec_line("3 to-float").unwrap();
+        assert!((interp.stack.pop_numeric().unwrap() - 3.0).abs() < f64::EPSILON);
+
+        interp.exec_line("0 to-bool").unwrap();
+        assert_eq!(interp.stack.pop_integer().unwrap(), 0);
+
+        interp.exec_line("42 to-string").unwrap();
+        assert_eq!(interp.stack.pop().unwrap().as_str().unwrap(), "42");
+    }
+
+    #[test]
+    fn conversion_error_names_source_and_target() {
+        let mut interp = make_interp();
+        interp.install_conversion_ops();
+
+        let err = interp.exec_line(r#""not a number" to-int"#).unwrap_err();
+        assert!(err.to_string().contains("string \"not a number\" to integer"));
+    }
+
+    #[test]
+    fn conversion_error_names_a_symbol_rather_than_a_string() {
+        let mut interp = make_interp();
+        interp.install_conversion_ops();
+
+        let err = interp.exec_line("undefined_op to-int").unwrap_err();
+        assert!(err.to_string().contains("cannot convert symbol to integer"));
+
+        let err = interp.exec_line("undefined_op to-float").unwrap_err();
+        assert!(err.to_string().contains("cannot convert symbol to float"));
+    }
+
+    #[test]
+    fn block_delta_tracks_open_and_close_words() {
+        assert_eq!(Interpreter::block_delta("1 若 10"), 1);
+        assert_eq!(Interpreter::block_delta("⺘"), -1);
+        assert_eq!(Interpreter::block_delta("若 ⺆ loop"), 3);
+        assert_eq!(Interpreter::block_delta("1 2 +"), 0);
+        assert_eq!(Interpreter::block_delta("再"), -1);
+        assert_eq!(Interpreter::block_delta("again"), -1);
+        assert_eq!(Interpreter::block_delta("until"), -1);
+        assert_eq!(Interpreter::block_delta("⟳ 1 + 再"), 0);
+    }
+
+    #[test]
+    fn to_dot_emits_a_digraph_with_nested_blocks() {
+        let mut interp = make_interp();
+        interp.exec_line("1 若 2 ⺘").unwrap();
+
+        let dot = interp.to_dot(GraphKind::Directed);
+        assert!(dot.starts_with("digraph blocks {"));
+
+        let undirected = interp.to_dot(GraphKind::Undirected);
+        assert!(undirected.starts_with("graph blocks {"));
+    }
+
+    #[test]
+    fn parse_timestamp_reads_format_from_next_token() {
+        let mut interp = make_interp();
+
+        interp
+            .exec_line(r#""1970-01-02" parse-timestamp "%Y-%m-%d""#)
+            .unwrap();
+
+        assert_eq!(interp.stack.pop_integer().unwrap(), 86_400);
+    }
+
+    #[test]
+    fn interning_the_same_text_twice_returns_the_same_id() {
+        let mut interp = make_interp();
+
+        let first = interp.intern("dup");
+        let second = interp.intern("dup");
+        assert_eq!(first, second);
+
+        let other = interp.intern("swap");
+        assert_ne!(first, other);
+    }
+
+    #[test]
+    fn resolve_round_trips_an_interned_name() {
+        let mut interp = make_interp();
+
+        let id = interp.intern("若");
+        assert_eq!(interp.resolve(id), "若");
+    }
+
+    #[test]
+    fn registered_handlers_stay_reachable_after_interning() {
+        let mut interp = make_interp();
+        interp.register("answer", |ctx| {
+            ctx.push(WofValue::integer(42));
+            Ok(())
+        });
+
+        interp.exec_line("answer").unwrap();
+        assert_eq!(interp.stack.pop_integer().unwrap(), 42);
+    }
+
+    #[test]
+    fn keyword_aliases_belong_to_the_same_keyword_as_their_glyph() {
+        let mut interp = make_interp();
+
+        let (glyph, alias) = (interp.intern("若"), interp.intern("if"));
+        assert!(interp.keywords.if_.contains(&glyph) && interp.keywords.if_.contains(&alias));
+        let (glyph, alias) = (interp.intern("読"), interp.intern("load"));
+        assert!(interp.keywords.read.contains(&glyph) && interp.keywords.read.contains(&alias));
+        let (glyph, alias) = (interp.intern("読"), interp.intern("get"));
+        assert!(interp.keywords.read.contains(&glyph) && interp.keywords.read.contains(&alias));
+    }
 }